@@ -9,6 +9,13 @@
 //! implement the [Queryable](trait.Queryable.html) trait for generalized
 //! querying interface.
 
+#[cfg(any(
+    feature = "postgresql",
+    feature = "mysql",
+    feature = "sqlite",
+    feature = "mssql"
+))]
+mod any;
 mod column_type;
 mod connection_info;
 
@@ -23,6 +30,13 @@ mod transaction;
 #[cfg(not(target_arch = "wasm32"))]
 mod type_identifier;
 
+#[cfg(any(
+    feature = "postgresql",
+    feature = "mysql",
+    feature = "sqlite",
+    feature = "mssql"
+))]
+pub use any::*;
 pub use self::result_set::*;
 pub use column_type::*;
 pub use connection_info::*;
@@ -0,0 +1,233 @@
+//! A connector that picks its concrete implementation at runtime rather than through
+//! compile-time `#[cfg(feature = ...)]` gates. Tools that embed quaint and don't know which
+//! database they'll be pointed at until a connection string is handed to them at startup can
+//! compile in several connector features and let [`AnyConnector::connect`] pick the right one,
+//! instead of shipping one binary per database.
+
+use async_trait::async_trait;
+
+use crate::{
+    ast::Query,
+    connector::{IsolationLevel, Queryable, ResultSet, Transaction, TransactionCapable},
+    error::{Error, ErrorKind},
+    prelude::SqlFamily,
+};
+
+#[cfg(feature = "mssql")]
+use crate::connector::Mssql;
+#[cfg(feature = "mysql")]
+use crate::connector::Mysql;
+#[cfg(feature = "postgresql")]
+use crate::connector::PostgreSql;
+#[cfg(feature = "sqlite")]
+use crate::connector::Sqlite;
+
+/// Dispatches to one of the compiled-in connectors based on the scheme of the connection
+/// string it was built from. Use [`AnyConnector::connect`] rather than constructing a variant
+/// directly.
+pub enum AnyConnector {
+    #[cfg(feature = "postgresql")]
+    Postgres(PostgreSql),
+    #[cfg(feature = "mysql")]
+    Mysql(Mysql),
+    #[cfg(feature = "sqlite")]
+    Sqlite(Sqlite),
+    #[cfg(feature = "mssql")]
+    Mssql(Mssql),
+}
+
+/// Forwards a method call to whichever variant is active, without requiring every feature to
+/// be compiled in (the match only ever sees the variants that exist).
+macro_rules! dispatch {
+    ($self:ident, $method:ident ( $($arg:expr),* )) => {
+        match $self {
+            #[cfg(feature = "postgresql")]
+            Self::Postgres(inner) => inner.$method($($arg),*).await,
+            #[cfg(feature = "mysql")]
+            Self::Mysql(inner) => inner.$method($($arg),*).await,
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(inner) => inner.$method($($arg),*).await,
+            #[cfg(feature = "mssql")]
+            Self::Mssql(inner) => inner.$method($($arg),*).await,
+        }
+    };
+}
+
+impl AnyConnector {
+    /// Builds the connector implied by `url`'s scheme (`postgres://`/`postgresql://`,
+    /// `mysql://`, `file:`/`sqlite://`, `sqlserver://`/`jdbc:sqlserver://`), connecting through
+    /// it immediately.
+    ///
+    /// Returns a [`ErrorKind::ConnectionError`] if the scheme is recognized but the
+    /// corresponding connector feature was not compiled into this binary, rather than failing
+    /// to compile at all.
+    pub async fn connect(url: &str) -> crate::Result<Self> {
+        let sql_family = SqlFamily::from_scheme(Self::scheme(url)?)
+            .ok_or_else(|| Self::unsupported_scheme(Self::scheme(url).unwrap_or(url)))?;
+
+        match sql_family {
+            SqlFamily::Postgres => Self::connect_postgres(url).await,
+            SqlFamily::Mysql => Self::connect_mysql(url).await,
+            SqlFamily::Sqlite => Self::connect_sqlite(url).await,
+            SqlFamily::Mssql => Self::connect_mssql(url).await,
+        }
+    }
+
+    fn scheme(url: &str) -> crate::Result<&str> {
+        // `jdbc:sqlserver://...` is the connection string shape our SQL Server tooling
+        // commonly receives; strip the `jdbc:` prefix before looking at the real scheme so it
+        // isn't mistaken for the scheme itself.
+        let url = url.strip_prefix("jdbc:").unwrap_or(url);
+        url.split_once(':').map(|(scheme, _)| scheme).ok_or_else(|| Self::unsupported_scheme(url))
+    }
+
+    #[cfg(feature = "postgresql")]
+    async fn connect_postgres(url: &str) -> crate::Result<Self> {
+        Ok(Self::Postgres(PostgreSql::new(url.parse()?).await?))
+    }
+
+    #[cfg(not(feature = "postgresql"))]
+    async fn connect_postgres(_url: &str) -> crate::Result<Self> {
+        Err(Self::driver_not_compiled_in("postgresql"))
+    }
+
+    #[cfg(feature = "mysql")]
+    async fn connect_mysql(url: &str) -> crate::Result<Self> {
+        Ok(Self::Mysql(Mysql::new(url.parse()?).await?))
+    }
+
+    #[cfg(not(feature = "mysql"))]
+    async fn connect_mysql(_url: &str) -> crate::Result<Self> {
+        Err(Self::driver_not_compiled_in("mysql"))
+    }
+
+    #[cfg(feature = "sqlite")]
+    async fn connect_sqlite(url: &str) -> crate::Result<Self> {
+        Ok(Self::Sqlite(Sqlite::new(url)?))
+    }
+
+    #[cfg(not(feature = "sqlite"))]
+    async fn connect_sqlite(_url: &str) -> crate::Result<Self> {
+        Err(Self::driver_not_compiled_in("sqlite"))
+    }
+
+    #[cfg(feature = "mssql")]
+    async fn connect_mssql(url: &str) -> crate::Result<Self> {
+        Ok(Self::Mssql(Mssql::new(url.parse()?).await?))
+    }
+
+    #[cfg(not(feature = "mssql"))]
+    async fn connect_mssql(_url: &str) -> crate::Result<Self> {
+        Err(Self::driver_not_compiled_in("mssql"))
+    }
+
+    fn unsupported_scheme(scheme: &str) -> Error {
+        Error::builder(ErrorKind::ConnectionError(
+            format!("`{scheme}` is not a recognized connection string scheme").into(),
+        ))
+        .build()
+    }
+
+    fn driver_not_compiled_in(name: &str) -> Error {
+        Error::builder(ErrorKind::ConnectionError(
+            format!("the `{name}` driver is not compiled into this binary; enable the `{name}` cargo feature").into(),
+        ))
+        .build()
+    }
+}
+
+#[async_trait]
+impl Queryable for AnyConnector {
+    async fn query(&self, q: Query<'_>) -> crate::Result<ResultSet> {
+        dispatch!(self, query(q))
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[crate::Value<'_>]) -> crate::Result<ResultSet> {
+        dispatch!(self, query_raw(sql, params))
+    }
+
+    async fn query_raw_typed(&self, sql: &str, params: &[crate::Value<'_>]) -> crate::Result<ResultSet> {
+        dispatch!(self, query_raw_typed(sql, params))
+    }
+
+    async fn execute(&self, q: Query<'_>) -> crate::Result<u64> {
+        dispatch!(self, execute(q))
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[crate::Value<'_>]) -> crate::Result<u64> {
+        dispatch!(self, execute_raw(sql, params))
+    }
+
+    async fn execute_raw_typed(&self, sql: &str, params: &[crate::Value<'_>]) -> crate::Result<u64> {
+        dispatch!(self, execute_raw_typed(sql, params))
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> crate::Result<()> {
+        dispatch!(self, raw_cmd(cmd))
+    }
+
+    async fn version(&self) -> crate::Result<Option<String>> {
+        dispatch!(self, version())
+    }
+
+    fn is_healthy(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgresql")]
+            Self::Postgres(inner) => inner.is_healthy(),
+            #[cfg(feature = "mysql")]
+            Self::Mysql(inner) => inner.is_healthy(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(inner) => inner.is_healthy(),
+            #[cfg(feature = "mssql")]
+            Self::Mssql(inner) => inner.is_healthy(),
+        }
+    }
+
+    fn requires_isolation_first(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgresql")]
+            Self::Postgres(inner) => inner.requires_isolation_first(),
+            #[cfg(feature = "mysql")]
+            Self::Mysql(inner) => inner.requires_isolation_first(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(inner) => inner.requires_isolation_first(),
+            #[cfg(feature = "mssql")]
+            Self::Mssql(inner) => inner.requires_isolation_first(),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionCapable for AnyConnector {
+    async fn start_transaction(&self, isolation: Option<IsolationLevel>) -> crate::Result<Box<dyn Transaction + '_>> {
+        dispatch!(self, start_transaction(isolation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheme_strips_jdbc_prefix_for_sqlserver() {
+        assert_eq!(AnyConnector::scheme("jdbc:sqlserver://localhost/db").unwrap(), "sqlserver");
+    }
+
+    #[test]
+    fn scheme_reads_the_plain_scheme_for_other_urls() {
+        assert_eq!(AnyConnector::scheme("postgresql://localhost/db").unwrap(), "postgresql");
+        assert_eq!(AnyConnector::scheme("mysql://localhost/db").unwrap(), "mysql");
+        assert_eq!(AnyConnector::scheme("file:dev.db").unwrap(), "file");
+    }
+
+    #[test]
+    fn scheme_rejects_a_url_without_a_colon() {
+        assert!(AnyConnector::scheme("not-a-url").is_err());
+    }
+
+    #[tokio::test]
+    async fn connect_rejects_an_unrecognized_scheme() {
+        let err = AnyConnector::connect("carrier-pigeon://localhost/db").await.unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::ConnectionError(_)));
+    }
+}
@@ -4,4 +4,11 @@ pub use crate::connector::{
     ColumnType, ConnectionInfo, DefaultTransaction, ExternalConnectionInfo, NativeConnectionInfo, Queryable, ResultRow,
     ResultSet, SqlFamily, TransactionCapable,
 };
+#[cfg(any(
+    feature = "postgresql",
+    feature = "mysql",
+    feature = "sqlite",
+    feature = "mssql"
+))]
+pub use crate::connector::AnyConnector;
 pub use crate::{col, val, values};